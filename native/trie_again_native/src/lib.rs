@@ -1,5 +1,5 @@
 use rustler::{Atom, Env, ResourceArc, Term};
-use trie_hard_rs::Trie;
+use trie_hard_rs::{RadixTrie, Trie};
 use std::sync::Mutex;
 
 mod atoms {
@@ -12,7 +12,7 @@ mod atoms {
 }
 
 pub struct TrieResource {
-    trie: Mutex<Trie<String>>,
+    trie: Mutex<Trie<char, String>>,
 }
 
 impl TrieResource {
@@ -23,6 +23,30 @@ impl TrieResource {
     }
 }
 
+pub struct ByteTrieResource {
+    trie: Mutex<Trie<u8, String>>,
+}
+
+impl ByteTrieResource {
+    fn new() -> Self {
+        ByteTrieResource {
+            trie: Mutex::new(Trie::new()),
+        }
+    }
+}
+
+pub struct RadixTrieResource {
+    trie: Mutex<RadixTrie<String>>,
+}
+
+impl RadixTrieResource {
+    fn new() -> Self {
+        RadixTrieResource {
+            trie: Mutex::new(RadixTrie::new()),
+        }
+    }
+}
+
 #[rustler::nif]
 fn new_trie() -> ResourceArc<TrieResource> {
     ResourceArc::new(TrieResource::new())
@@ -32,7 +56,7 @@ fn new_trie() -> ResourceArc<TrieResource> {
 fn insert(trie_resource: ResourceArc<TrieResource>, key: String, value: String) -> Atom {
     match trie_resource.trie.lock() {
         Ok(mut trie) => {
-            trie.insert(&key, &value);
+            trie.insert_str(&key, &value);
             atoms::ok()
         }
         Err(_) => atoms::error()
@@ -43,7 +67,7 @@ fn insert(trie_resource: ResourceArc<TrieResource>, key: String, value: String)
 fn get(trie_resource: ResourceArc<TrieResource>, key: String) -> (Atom, Option<String>) {
     match trie_resource.trie.lock() {
         Ok(trie) => {
-            match trie.get(&key) {
+            match trie.get_str(&key) {
                 Some(value) => (atoms::ok(), Some(value.clone())),
                 None => (atoms::not_found(), None)
             }
@@ -56,7 +80,7 @@ fn get(trie_resource: ResourceArc<TrieResource>, key: String) -> (Atom, Option<S
 fn delete(trie_resource: ResourceArc<TrieResource>, key: String) -> Atom {
     match trie_resource.trie.lock() {
         Ok(mut trie) => {
-            trie.delete(&key);
+            trie.delete_str(&key);
             atoms::ok()
         }
         Err(_) => atoms::error()
@@ -66,7 +90,7 @@ fn delete(trie_resource: ResourceArc<TrieResource>, key: String) -> Atom {
 #[rustler::nif]
 fn prefix_search(trie_resource: ResourceArc<TrieResource>, prefix: String) -> (Atom, bool) {
     match trie_resource.trie.lock() {
-        Ok(trie) => (atoms::ok(), trie.prefix_search(&prefix)),
+        Ok(trie) => (atoms::ok(), trie.prefix_search_str(&prefix)),
         Err(_) => (atoms::error(), false)
     }
 }
@@ -82,6 +106,102 @@ fn auto_complete(trie_resource: ResourceArc<TrieResource>, prefix: String, max_r
     }
 }
 
+#[rustler::nif]
+fn find_prefixes(trie_resource: ResourceArc<TrieResource>, key: String) -> (Atom, Vec<(String, String)>) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => {
+            let results = trie
+                .find_prefixes_str(&key)
+                .into_iter()
+                .map(|(prefix, value)| (prefix, value.clone()))
+                .collect();
+            (atoms::ok(), results)
+        }
+        Err(_) => (atoms::error(), Vec::new())
+    }
+}
+
+#[rustler::nif]
+fn find_longest_prefix(trie_resource: ResourceArc<TrieResource>, key: String) -> (Atom, Option<(String, String)>) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => {
+            match trie.find_longest_prefix_str(&key) {
+                Some((prefix, value)) => (atoms::ok(), Some((prefix, value.clone()))),
+                None => (atoms::not_found(), None)
+            }
+        }
+        Err(_) => (atoms::error(), None)
+    }
+}
+
+#[rustler::nif]
+fn insert_weighted(trie_resource: ResourceArc<TrieResource>, key: String, value: String, weight: i64) -> Atom {
+    match trie_resource.trie.lock() {
+        Ok(mut trie) => {
+            trie.insert_weighted_str(&key, &value, weight);
+            atoms::ok()
+        }
+        Err(_) => atoms::error()
+    }
+}
+
+#[rustler::nif]
+fn auto_complete_ranked(trie_resource: ResourceArc<TrieResource>, prefix: String, max_results: usize) -> (Atom, Vec<(String, i64)>) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => {
+            let results = trie.auto_complete_ranked(&prefix, max_results);
+            (atoms::ok(), results)
+        }
+        Err(_) => (atoms::error(), Vec::new())
+    }
+}
+
+#[rustler::nif]
+fn fuzzy_search(trie_resource: ResourceArc<TrieResource>, query: String, max_distance: usize, max_results: usize) -> (Atom, Vec<(String, usize)>) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => {
+            let results = trie.fuzzy_search(&query, max_distance, max_results);
+            (atoms::ok(), results)
+        }
+        Err(_) => (atoms::error(), Vec::new())
+    }
+}
+
+#[rustler::nif]
+fn entries(trie_resource: ResourceArc<TrieResource>) -> (Atom, Vec<(String, String)>) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => {
+            let results = trie
+                .iter_sorted()
+                .map(|(key, value)| (key, value.clone()))
+                .collect();
+            (atoms::ok(), results)
+        }
+        Err(_) => (atoms::error(), Vec::new())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[rustler::nif]
+fn dump(trie_resource: ResourceArc<TrieResource>) -> (Atom, Option<Vec<u8>>) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => match trie.to_bytes() {
+            Ok(bytes) => (atoms::ok(), Some(bytes)),
+            Err(_) => (atoms::error(), None)
+        },
+        Err(_) => (atoms::error(), None)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[rustler::nif]
+fn load_trie(bytes: Vec<u8>) -> (Atom, Option<ResourceArc<TrieResource>>) {
+    match Trie::from_bytes(&bytes) {
+        Ok(trie) => (atoms::ok(), Some(ResourceArc::new(TrieResource { trie: Mutex::new(trie) }))),
+        Err(_) => (atoms::error(), None)
+    }
+}
+
 #[rustler::nif]
 fn add_word_list(trie_resource: ResourceArc<TrieResource>, words: Vec<String>) -> Atom {
     match trie_resource.trie.lock() {
@@ -94,8 +214,98 @@ fn add_word_list(trie_resource: ResourceArc<TrieResource>, words: Vec<String>) -
     }
 }
 
+#[rustler::nif]
+fn new_byte_trie() -> ResourceArc<ByteTrieResource> {
+    ResourceArc::new(ByteTrieResource::new())
+}
+
+#[rustler::nif]
+fn byte_insert(trie_resource: ResourceArc<ByteTrieResource>, key: Vec<u8>, value: String) -> Atom {
+    match trie_resource.trie.lock() {
+        Ok(mut trie) => {
+            trie.insert(key.into_iter(), &value);
+            atoms::ok()
+        }
+        Err(_) => atoms::error()
+    }
+}
+
+#[rustler::nif]
+fn byte_get(trie_resource: ResourceArc<ByteTrieResource>, key: Vec<u8>) -> (Atom, Option<String>) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => {
+            match trie.get(key.into_iter()) {
+                Some(value) => (atoms::ok(), Some(value.clone())),
+                None => (atoms::not_found(), None)
+            }
+        }
+        Err(_) => (atoms::error(), None)
+    }
+}
+
+#[rustler::nif]
+fn byte_delete(trie_resource: ResourceArc<ByteTrieResource>, key: Vec<u8>) -> Atom {
+    match trie_resource.trie.lock() {
+        Ok(mut trie) => {
+            trie.delete(key.into_iter());
+            atoms::ok()
+        }
+        Err(_) => atoms::error()
+    }
+}
+
+#[rustler::nif]
+fn new_radix_trie() -> ResourceArc<RadixTrieResource> {
+    ResourceArc::new(RadixTrieResource::new())
+}
+
+#[rustler::nif]
+fn radix_insert(trie_resource: ResourceArc<RadixTrieResource>, key: String, value: String) -> Atom {
+    match trie_resource.trie.lock() {
+        Ok(mut trie) => {
+            trie.insert(&key, &value);
+            atoms::ok()
+        }
+        Err(_) => atoms::error()
+    }
+}
+
+#[rustler::nif]
+fn radix_get(trie_resource: ResourceArc<RadixTrieResource>, key: String) -> (Atom, Option<String>) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => {
+            match trie.get(&key) {
+                Some(value) => (atoms::ok(), Some(value.clone())),
+                None => (atoms::not_found(), None)
+            }
+        }
+        Err(_) => (atoms::error(), None)
+    }
+}
+
+#[rustler::nif]
+fn radix_prefix_search(trie_resource: ResourceArc<RadixTrieResource>, prefix: String) -> (Atom, bool) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => (atoms::ok(), trie.prefix_search(&prefix)),
+        Err(_) => (atoms::error(), false)
+    }
+}
+
+#[rustler::nif]
+fn radix_auto_complete(trie_resource: ResourceArc<RadixTrieResource>, prefix: String, max_results: usize) -> (Atom, Vec<String>) {
+    match trie_resource.trie.lock() {
+        Ok(trie) => {
+            let results = trie.auto_complete(&prefix, max_results);
+            (atoms::ok(), results)
+        }
+        Err(_) => (atoms::error(), Vec::new())
+    }
+}
+
 fn load(env: Env, _info: Term) -> bool {
     let _ = rustler::resource!(TrieResource, env);
+    let _ = rustler::resource!(ByteTrieResource, env);
+    let _ = rustler::resource!(RadixTrieResource, env);
     true
 }
 
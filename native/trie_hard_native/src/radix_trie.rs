@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.chars()
+        .zip(b.chars())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum()
+}
+
+#[derive(Debug, Clone)]
+struct RadixEdge<T> {
+    label: String,
+    node: RadixNode<T>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RadixNode<T> {
+    pub value: Option<T>,
+    children: HashMap<char, RadixEdge<T>>,
+}
+
+impl<T> RadixNode<T> {
+    fn new() -> Self {
+        RadixNode {
+            value: None,
+            children: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RadixTrie<T> {
+    root: RadixNode<T>,
+}
+
+impl<T: Clone> RadixTrie<T> {
+    pub fn new() -> Self {
+        RadixTrie {
+            root: RadixNode::new(),
+        }
+    }
+
+    pub fn insert(&mut self, key: &str, value: &T) {
+        Self::insert_at(&mut self.root, key, value);
+    }
+
+    fn insert_at(node: &mut RadixNode<T>, remaining: &str, value: &T) {
+        if remaining.is_empty() {
+            node.value = Some(value.clone());
+            return;
+        }
+
+        let first = remaining.chars().next().unwrap();
+        match node.children.get_mut(&first) {
+            None => {
+                node.children.insert(
+                    first,
+                    RadixEdge {
+                        label: remaining.to_string(),
+                        node: Self::leaf(value),
+                    },
+                );
+            }
+            Some(edge) => {
+                let common = common_prefix_len(&edge.label, remaining);
+                if common == edge.label.len() {
+                    Self::insert_at(&mut edge.node, &remaining[common..], value);
+                } else {
+                    Self::split_edge(edge, common);
+                    let new_suffix = &remaining[common..];
+                    if new_suffix.is_empty() {
+                        edge.node.value = Some(value.clone());
+                    } else {
+                        let new_first = new_suffix.chars().next().unwrap();
+                        edge.node.children.insert(
+                            new_first,
+                            RadixEdge {
+                                label: new_suffix.to_string(),
+                                node: Self::leaf(value),
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn leaf(value: &T) -> RadixNode<T> {
+        let mut node = RadixNode::new();
+        node.value = Some(value.clone());
+        node
+    }
+
+    fn split_edge(edge: &mut RadixEdge<T>, common: usize) {
+        let new_label = edge.label[..common].to_string();
+        let old_label = std::mem::replace(&mut edge.label, new_label);
+        let old_node = std::mem::replace(&mut edge.node, RadixNode::new());
+
+        let old_suffix = old_label[common..].to_string();
+        let old_first = old_suffix.chars().next().unwrap();
+        edge.node.children.insert(
+            old_first,
+            RadixEdge {
+                label: old_suffix,
+                node: old_node,
+            },
+        );
+    }
+
+    pub fn get(&self, key: &str) -> Option<&T> {
+        let mut node = &self.root;
+        let mut remaining = key;
+
+        while !remaining.is_empty() {
+            let first = remaining.chars().next().unwrap();
+            match node.children.get(&first) {
+                Some(edge) if remaining.starts_with(edge.label.as_str()) => {
+                    remaining = &remaining[edge.label.len()..];
+                    node = &edge.node;
+                }
+                _ => return None,
+            }
+        }
+
+        node.value.as_ref()
+    }
+
+    pub fn prefix_search(&self, prefix: &str) -> bool {
+        let mut node = &self.root;
+        let mut remaining = prefix;
+
+        while !remaining.is_empty() {
+            let first = remaining.chars().next().unwrap();
+            match node.children.get(&first) {
+                Some(edge) => {
+                    let common = common_prefix_len(&edge.label, remaining);
+                    if common == remaining.len() {
+                        // The query ends inside (or exactly at) this edge - a match either way.
+                        return true;
+                    }
+                    if common < edge.label.len() {
+                        return false;
+                    }
+                    remaining = &remaining[common..];
+                    node = &edge.node;
+                }
+                None => return false,
+            }
+        }
+
+        true
+    }
+
+    pub fn auto_complete(&self, prefix: &str, max_results: usize) -> Vec<String> {
+        let mut node = &self.root;
+        let mut remaining = prefix;
+        let mut accumulated = String::new();
+
+        while !remaining.is_empty() {
+            let first = remaining.chars().next().unwrap();
+            match node.children.get(&first) {
+                Some(edge) => {
+                    let common = common_prefix_len(&edge.label, remaining);
+                    if common < edge.label.len() && common < remaining.len() {
+                        return Vec::new();
+                    }
+                    accumulated.push_str(&edge.label);
+                    node = &edge.node;
+                    remaining = if common < edge.label.len() {
+                        ""
+                    } else {
+                        &remaining[common..]
+                    };
+                }
+                None => return Vec::new(),
+            }
+        }
+
+        let mut results = Vec::new();
+        self.collect_words(node, accumulated, &mut results, max_results);
+        results
+    }
+
+    fn collect_words(
+        &self,
+        node: &RadixNode<T>,
+        prefix: String,
+        results: &mut Vec<String>,
+        max_results: usize,
+    ) {
+        if results.len() >= max_results {
+            return;
+        }
+
+        if node.value.is_some() {
+            results.push(prefix.clone());
+        }
+
+        for edge in node.children.values() {
+            if results.len() >= max_results {
+                break;
+            }
+            let mut new_prefix = prefix.clone();
+            new_prefix.push_str(&edge.label);
+            self.collect_words(&edge.node, new_prefix, results, max_results);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_splits_edges_on_partial_label_match() {
+        let mut trie: RadixTrie<i32> = RadixTrie::new();
+        trie.insert("romane", &1);
+        trie.insert("romanus", &2);
+        trie.insert("romulus", &3);
+
+        assert_eq!(trie.get("romane"), Some(&1));
+        assert_eq!(trie.get("romanus"), Some(&2));
+        assert_eq!(trie.get("romulus"), Some(&3));
+        assert_eq!(trie.get("roman"), None);
+        assert!(trie.prefix_search("roman"));
+        assert!(trie.prefix_search("rom"));
+        assert!(!trie.prefix_search("rome"));
+    }
+
+    #[test]
+    fn auto_complete_matches_prefixes_ending_mid_edge() {
+        let mut trie: RadixTrie<i32> = RadixTrie::new();
+        trie.insert("romane", &1);
+        trie.insert("romanus", &2);
+
+        let mut results = trie.auto_complete("roma", 10);
+        results.sort();
+        assert_eq!(results, vec!["romane".to_string(), "romanus".to_string()]);
+    }
+}
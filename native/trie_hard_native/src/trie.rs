@@ -1,44 +1,91 @@
 use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone)]
-pub struct TrieNode<T> {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "K: Eq + Hash + Clone + Serialize, T: Clone + Serialize",
+        deserialize = "K: Eq + Hash + Clone + Deserialize<'de>, T: Clone + Deserialize<'de>"
+    ))
+)]
+pub struct TrieNode<K, T> {
     pub value: Option<T>,
-    pub children: HashMap<char, TrieNode<T>>,
+    pub weight: i64,
+    pub children: HashMap<K, TrieNode<K, T>>,
 }
 
-impl<T> TrieNode<T> {
+impl<K: Eq + Hash + Clone, T> TrieNode<K, T> {
     pub fn new() -> Self {
         TrieNode {
             value: None,
+            weight: 0,
             children: HashMap::new(),
         }
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct Trie<T> {
-    root: TrieNode<T>,
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "K: Eq + Hash + Clone + Serialize, T: Clone + Serialize",
+        deserialize = "K: Eq + Hash + Clone + Deserialize<'de>, T: Clone + Deserialize<'de>"
+    ))
+)]
+pub struct Trie<K, T> {
+    root: TrieNode<K, T>,
+}
+
+#[cfg(feature = "serde")]
+impl<K, T> Trie<K, T>
+where
+    K: Eq + Hash + Clone + Serialize + serde::de::DeserializeOwned,
+    T: Clone + Serialize + serde::de::DeserializeOwned,
+{
+    pub fn to_bytes(&self) -> Result<Vec<u8>, bincode::Error> {
+        bincode::serialize(self)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
 }
 
-impl<T: Clone> Trie<T> {
+impl<K: Eq + Hash + Clone, T: Clone> Trie<K, T> {
     pub fn new() -> Self {
         Trie {
             root: TrieNode::new(),
         }
     }
 
-    pub fn insert(&mut self, key: &str, value: &T) {
+    pub fn insert(&mut self, key: impl Iterator<Item = K>, value: &T) {
         let mut current = &mut self.root;
-        for ch in key.chars() {
-            current = current.children.entry(ch).or_insert_with(TrieNode::new);
+        for elem in key {
+            current = current.children.entry(elem).or_insert_with(TrieNode::new);
         }
         current.value = Some(value.clone());
+        current.weight = 0;
     }
 
-    pub fn get(&self, key: &str) -> Option<&T> {
+    pub fn insert_weighted(&mut self, key: impl Iterator<Item = K>, value: &T, weight: i64) {
+        let mut current = &mut self.root;
+        for elem in key {
+            current = current.children.entry(elem).or_insert_with(TrieNode::new);
+        }
+        current.value = Some(value.clone());
+        current.weight = weight;
+    }
+
+    pub fn get(&self, key: impl Iterator<Item = K>) -> Option<&T> {
         let mut current = &self.root;
-        for ch in key.chars() {
-            match current.children.get(&ch) {
+        for elem in key {
+            match current.children.get(&elem) {
                 Some(node) => current = node,
                 None => return None,
             }
@@ -46,11 +93,12 @@ impl<T: Clone> Trie<T> {
         current.value.as_ref()
     }
 
-    pub fn delete(&mut self, key: &str) {
-        Trie::delete_recursive(&mut self.root, key, 0);
+    pub fn delete(&mut self, key: impl Iterator<Item = K>) {
+        let key: Vec<K> = key.collect();
+        Trie::delete_recursive(&mut self.root, &key, 0);
     }
 
-    fn delete_recursive(node: &mut TrieNode<T>, key: &str, index: usize) -> bool {
+    fn delete_recursive(node: &mut TrieNode<K, T>, key: &[K], index: usize) -> bool {
         if index == key.len() {
             if node.value.is_some() {
                 node.value = None;
@@ -59,21 +107,21 @@ impl<T: Clone> Trie<T> {
             return false;
         }
 
-        let ch = key.chars().nth(index).unwrap();
-        if let Some(child) = node.children.get_mut(&ch) {
+        let elem = &key[index];
+        if let Some(child) = node.children.get_mut(elem) {
             let should_delete_child = Trie::delete_recursive(child, key, index + 1);
             if should_delete_child {
-                node.children.remove(&ch);
+                node.children.remove(elem);
             }
         }
 
         node.value.is_none() && node.children.is_empty()
     }
 
-    pub fn prefix_search(&self, prefix: &str) -> bool {
+    pub fn prefix_search(&self, prefix: impl Iterator<Item = K>) -> bool {
         let mut current = &self.root;
-        for ch in prefix.chars() {
-            match current.children.get(&ch) {
+        for elem in prefix {
+            match current.children.get(&elem) {
                 Some(node) => current = node,
                 None => return false,
             }
@@ -81,6 +129,70 @@ impl<T: Clone> Trie<T> {
         true
     }
 
+    pub fn find_prefixes(&self, key: impl Iterator<Item = K>) -> Vec<(Vec<K>, &T)> {
+        let mut current = &self.root;
+        let mut prefix = Vec::new();
+        let mut results = Vec::new();
+
+        for elem in key {
+            match current.children.get(&elem) {
+                Some(node) => current = node,
+                None => break,
+            }
+            prefix.push(elem);
+            if let Some(value) = current.value.as_ref() {
+                results.push((prefix.clone(), value));
+            }
+        }
+
+        results
+    }
+
+    pub fn find_longest_prefix(&self, key: impl Iterator<Item = K>) -> Option<(Vec<K>, &T)> {
+        self.find_prefixes(key).pop()
+    }
+}
+
+struct FuzzySearchCtx<'a> {
+    query: &'a [char],
+    max_distance: usize,
+    max_results: usize,
+    heap: std::collections::BinaryHeap<(usize, String)>,
+}
+
+impl<T: Clone> Trie<char, T> {
+    pub fn insert_str(&mut self, key: &str, value: &T) {
+        self.insert(key.chars(), value);
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&T> {
+        self.get(key.chars())
+    }
+
+    pub fn delete_str(&mut self, key: &str) {
+        self.delete(key.chars());
+    }
+
+    pub fn prefix_search_str(&self, prefix: &str) -> bool {
+        self.prefix_search(prefix.chars())
+    }
+
+    pub fn insert_weighted_str(&mut self, key: &str, value: &T, weight: i64) {
+        self.insert_weighted(key.chars(), value, weight);
+    }
+
+    pub fn find_prefixes_str(&self, key: &str) -> Vec<(String, &T)> {
+        self.find_prefixes(key.chars())
+            .into_iter()
+            .map(|(chars, value)| (chars.into_iter().collect(), value))
+            .collect()
+    }
+
+    pub fn find_longest_prefix_str(&self, key: &str) -> Option<(String, &T)> {
+        self.find_longest_prefix(key.chars())
+            .map(|(chars, value)| (chars.into_iter().collect(), value))
+    }
+
     pub fn auto_complete(&self, prefix: &str, max_results: usize) -> Vec<String> {
         let mut current = &self.root;
         for ch in prefix.chars() {
@@ -97,7 +209,7 @@ impl<T: Clone> Trie<T> {
 
     fn collect_words(
         &self,
-        node: &TrieNode<T>,
+        node: &TrieNode<char, T>,
         prefix: String,
         results: &mut Vec<String>,
         max_results: usize,
@@ -126,7 +238,276 @@ impl<T: Clone> Trie<T> {
     {
         for word in words {
             let value = value_mapper(word);
-            self.insert(word, &value);
+            self.insert_str(word, &value);
         }
     }
-}
\ No newline at end of file
+
+    pub fn auto_complete_ranked(&self, prefix: &str, max_results: usize) -> Vec<(String, i64)> {
+        use std::cmp::Reverse;
+        use std::collections::BinaryHeap;
+
+        let mut current = &self.root;
+        for ch in prefix.chars() {
+            match current.children.get(&ch) {
+                Some(node) => current = node,
+                None => return Vec::new(),
+            }
+        }
+
+        let mut heap: BinaryHeap<Reverse<(i64, String)>> = BinaryHeap::new();
+        self.collect_weighted(current, prefix.to_string(), max_results, &mut heap);
+
+        let mut results: Vec<(String, i64)> = heap
+            .into_iter()
+            .map(|Reverse((weight, word))| (word, weight))
+            .collect();
+        results.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        results
+    }
+
+    fn collect_weighted(
+        &self,
+        node: &TrieNode<char, T>,
+        prefix: String,
+        max_results: usize,
+        heap: &mut std::collections::BinaryHeap<std::cmp::Reverse<(i64, String)>>,
+    ) {
+        use std::cmp::Reverse;
+
+        if node.value.is_some() {
+            heap.push(Reverse((node.weight, prefix.clone())));
+            if heap.len() > max_results {
+                heap.pop();
+            }
+        }
+
+        for (ch, child) in &node.children {
+            let mut new_prefix = prefix.clone();
+            new_prefix.push(*ch);
+            self.collect_weighted(child, new_prefix, max_results, heap);
+        }
+    }
+
+    pub fn iter(&self) -> std::vec::IntoIter<(String, &T)> {
+        let mut results = Vec::new();
+        self.collect_entries(&self.root, String::new(), &mut results);
+        results.into_iter()
+    }
+
+    fn collect_entries<'a>(
+        &'a self,
+        node: &'a TrieNode<char, T>,
+        prefix: String,
+        results: &mut Vec<(String, &'a T)>,
+    ) {
+        if let Some(value) = node.value.as_ref() {
+            results.push((prefix.clone(), value));
+        }
+
+        for (ch, child) in &node.children {
+            let mut new_prefix = prefix.clone();
+            new_prefix.push(*ch);
+            self.collect_entries(child, new_prefix, results);
+        }
+    }
+
+    pub fn iter_sorted(&self) -> std::vec::IntoIter<(String, &T)> {
+        let mut results = Vec::new();
+        self.collect_entries_sorted(&self.root, String::new(), &mut results);
+        results.into_iter()
+    }
+
+    fn collect_entries_sorted<'a>(
+        &'a self,
+        node: &'a TrieNode<char, T>,
+        prefix: String,
+        results: &mut Vec<(String, &'a T)>,
+    ) {
+        if let Some(value) = node.value.as_ref() {
+            results.push((prefix.clone(), value));
+        }
+
+        let mut keys: Vec<&char> = node.children.keys().collect();
+        keys.sort();
+        for ch in keys {
+            let child = &node.children[ch];
+            let mut new_prefix = prefix.clone();
+            new_prefix.push(*ch);
+            self.collect_entries_sorted(child, new_prefix, results);
+        }
+    }
+
+    pub fn fuzzy_search(
+        &self,
+        query: &str,
+        max_distance: usize,
+        max_results: usize,
+    ) -> Vec<(String, usize)> {
+        use std::collections::BinaryHeap;
+
+        let query: Vec<char> = query.chars().collect();
+        let initial_row: Vec<usize> = (0..=query.len()).collect();
+
+        let mut ctx = FuzzySearchCtx {
+            query: &query,
+            max_distance,
+            max_results,
+            heap: BinaryHeap::new(),
+        };
+        self.fuzzy_search_recursive(&self.root, String::new(), &initial_row, &mut ctx);
+
+        let mut results: Vec<(String, usize)> = ctx
+            .heap
+            .into_iter()
+            .map(|(distance, word)| (word, distance))
+            .collect();
+        results.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        results
+    }
+
+    fn fuzzy_search_recursive(
+        &self,
+        node: &TrieNode<char, T>,
+        prefix: String,
+        prev_row: &[usize],
+        ctx: &mut FuzzySearchCtx,
+    ) {
+        let distance = prev_row[ctx.query.len()];
+        if node.value.is_some() && distance <= ctx.max_distance {
+            ctx.heap.push((distance, prefix.clone()));
+            if ctx.heap.len() > ctx.max_results {
+                ctx.heap.pop();
+            }
+        }
+
+        if prev_row.iter().copied().min().unwrap_or(0) > ctx.max_distance {
+            return;
+        }
+
+        for (&ch, child) in &node.children {
+            let mut row = Vec::with_capacity(prev_row.len());
+            row.push(prev_row[0] + 1);
+            for i in 1..=ctx.query.len() {
+                let cost = if ctx.query[i - 1] == ch { 0 } else { 1 };
+                row.push((row[i - 1] + 1).min(prev_row[i] + 1).min(prev_row[i - 1] + cost));
+            }
+
+            let mut new_prefix = prefix.clone();
+            new_prefix.push(ch);
+            self.fuzzy_search_recursive(child, new_prefix, &row, ctx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_search_ranks_by_distance_not_traversal_order() {
+        let mut trie: Trie<char, i32> = Trie::new();
+        trie.insert_str("abcd", &1);
+        trie.insert_str("zbcd", &2);
+
+        let results = trie.fuzzy_search("abcd", 1, 1);
+
+        assert_eq!(results, vec![("abcd".to_string(), 0)]);
+    }
+
+    #[test]
+    fn find_longest_prefix_picks_the_longest_stored_prefix() {
+        let mut trie: Trie<char, i32> = Trie::new();
+        trie.insert_str("a", &1);
+        trie.insert_str("ab", &2);
+        trie.insert_str("abc", &3);
+
+        assert_eq!(
+            trie.find_longest_prefix_str("abcd"),
+            Some(("abc".to_string(), &3))
+        );
+        assert_eq!(
+            trie.find_prefixes_str("abcd"),
+            vec![
+                ("a".to_string(), &1),
+                ("ab".to_string(), &2),
+                ("abc".to_string(), &3),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_longest_prefix_is_none_when_no_stored_key_is_a_prefix() {
+        let mut trie: Trie<char, i32> = Trie::new();
+        trie.insert_str("xyz", &1);
+
+        assert_eq!(trie.find_longest_prefix_str("abc"), None);
+    }
+
+    #[test]
+    fn generic_trie_indexes_non_char_keys() {
+        let mut trie: Trie<u8, &str> = Trie::new();
+        trie.insert(b"key".iter().copied(), &"value");
+
+        assert_eq!(trie.get(b"key".iter().copied()), Some(&"value"));
+        assert_eq!(trie.get(b"nope".iter().copied()), None);
+        assert!(trie.prefix_search(b"ke".iter().copied()));
+
+        trie.delete(b"key".iter().copied());
+        assert_eq!(trie.get(b"key".iter().copied()), None);
+    }
+
+    #[test]
+    fn auto_complete_ranked_orders_by_weight_descending() {
+        let mut trie: Trie<char, i32> = Trie::new();
+        trie.insert_weighted_str("cat", &1, 5);
+        trie.insert_weighted_str("car", &2, 50);
+        trie.insert_weighted_str("cart", &3, 20);
+
+        let results = trie.auto_complete_ranked("ca", 2);
+
+        assert_eq!(
+            results,
+            vec![("car".to_string(), 50), ("cart".to_string(), 20)]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn to_bytes_from_bytes_round_trips() {
+        let mut trie: Trie<char, String> = Trie::new();
+        trie.insert_weighted_str("cat", &"meow".to_string(), 5);
+        trie.insert_str("car", &"vroom".to_string());
+
+        let bytes = trie.to_bytes().unwrap();
+        let restored: Trie<char, String> = Trie::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.get_str("cat"), Some(&"meow".to_string()));
+        assert_eq!(restored.get_str("car"), Some(&"vroom".to_string()));
+        assert_eq!(restored.get_str("missing"), None);
+        assert_eq!(
+            restored.auto_complete_ranked("ca", 2),
+            trie.auto_complete_ranked("ca", 2)
+        );
+    }
+
+    #[test]
+    fn iter_sorted_yields_keys_in_lexicographic_order() {
+        let mut trie: Trie<char, i32> = Trie::new();
+        trie.insert_str("banana", &1);
+        trie.insert_str("apple", &2);
+        trie.insert_str("cherry", &3);
+        trie.insert_str("a", &4);
+
+        let keys: Vec<String> = trie.iter_sorted().map(|(key, _)| key).collect();
+
+        assert_eq!(
+            keys,
+            vec![
+                "a".to_string(),
+                "apple".to_string(),
+                "banana".to_string(),
+                "cherry".to_string(),
+            ]
+        );
+    }
+}